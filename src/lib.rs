@@ -11,20 +11,51 @@ use embedded_hal::blocking::i2c::{
 	WriteRead,
 };
 
+#[cfg(feature = "async")]
+use embedded_hal_async::i2c::I2c as AsyncI2c;
+
 use bitfield::bitfield;
 
 pub struct Bq24195<I2C> {
 	i2c: I2C,
+	thermal: ThermalMitigation,
+}
+
+/// Host side thermal-mitigation state. The charge current is tracked as an
+/// index into the eight step [`InputCurrentLimit`] ladder so it can be stepped
+/// down under heat and walked back up again once things cool off.
+struct ThermalMitigation {
+	ceiling: u8,
+	applied: u8,
+	consecutive_normal: u8,
+	/// Whether `ceiling`/`applied` reflect the real register yet. Until the
+	/// first step (or an explicit ceiling) they are placeholders and must not
+	/// be pushed onto the chip.
+	seeded: bool,
+}
+
+impl Default for ThermalMitigation {
+	fn default() -> Self {
+		ThermalMitigation {
+			ceiling: InputCurrentLimit::MA100 as u8,
+			applied: InputCurrentLimit::MA100 as u8,
+			consecutive_normal: 0,
+			seeded: false,
+		}
+	}
 }
 
 pub enum Error<E> {
 	I2C(E),
+	/// D+/D- detection did not self-clear within the allotted polls.
+	DetectionTimeout,
 }
 
 impl<E> Debug for Error<E> where E: Debug {
 	fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
 		match self {
 			Error::I2C(e) => write!(f, "Error::I2C({:?})", e),
+			Error::DetectionTimeout => write!(f, "Error::DetectionTimeout"),
 		}
 	}
 }
@@ -192,6 +223,226 @@ impl Default for PowerOnConfiguration {
 	}
 }
 
+bitfield! {
+	pub struct ChargeCurrentControl(u8);
+	impl Debug;
+
+	pub u8, fast_charge_current, set_fast_charge_current : 7, 2;
+}
+
+impl ChargeCurrentControl {
+	/// Build a fast-charge current control register from a value in
+	/// milliamps, clamping to the 512..=4544 mA range the part supports.
+	pub fn from_milliamps(milliamps: u16) -> Self {
+		let mut reg = ChargeCurrentControl(0);
+		reg.set_milliamps(milliamps);
+		reg
+	}
+
+	/// Encode a fast-charge current in milliamps. `ICHG` has a 512 mA offset
+	/// and 64 mA steps; out of range values are clamped to the nearest legal
+	/// step.
+	pub fn set_milliamps(&mut self, milliamps: u16) {
+		let milliamps = milliamps.clamp(512, 4544);
+		self.set_fast_charge_current(((milliamps - 512) / 64) as u8);
+	}
+
+	pub fn to_milliamps(&self) -> u16 {
+		512 + u16::from(self.fast_charge_current()) * 64
+	}
+}
+
+impl Default for ChargeCurrentControl {
+	fn default() -> Self {
+		ChargeCurrentControl::from_milliamps(2048)
+	}
+}
+
+bitfield! {
+	pub struct PreChargeTerminationCurrentControl(u8);
+	impl Debug;
+
+	pub u8, precharge_current, set_precharge_current : 7, 4;
+	pub u8, termination_current, set_termination_current : 3, 0;
+}
+
+impl PreChargeTerminationCurrentControl {
+	/// Build the pre-charge / termination current register from the two
+	/// currents in milliamps, clamping each to 128..=2048 mA.
+	pub fn from_milliamps(precharge: u16, termination: u16) -> Self {
+		let mut reg = PreChargeTerminationCurrentControl(0);
+		reg.set_precharge_milliamps(precharge);
+		reg.set_termination_milliamps(termination);
+		reg
+	}
+
+	/// Encode the pre-charge current in milliamps. `IPRECHG` has a 128 mA
+	/// offset and 128 mA steps; out of range values are clamped.
+	pub fn set_precharge_milliamps(&mut self, milliamps: u16) {
+		self.set_precharge_current(Self::encode(milliamps));
+	}
+
+	/// Encode the termination current in milliamps. `ITERM` has a 128 mA
+	/// offset and 128 mA steps; out of range values are clamped.
+	pub fn set_termination_milliamps(&mut self, milliamps: u16) {
+		self.set_termination_current(Self::encode(milliamps));
+	}
+
+	pub fn precharge_to_milliamps(&self) -> u16 {
+		128 + u16::from(self.precharge_current()) * 128
+	}
+
+	pub fn termination_to_milliamps(&self) -> u16 {
+		128 + u16::from(self.termination_current()) * 128
+	}
+
+	fn encode(milliamps: u16) -> u8 {
+		let milliamps = milliamps.clamp(128, 2048);
+		((milliamps - 128) / 128) as u8
+	}
+}
+
+impl Default for PreChargeTerminationCurrentControl {
+	fn default() -> Self {
+		PreChargeTerminationCurrentControl::from_milliamps(128, 128)
+	}
+}
+
+bitfield! {
+	pub struct ChargeVoltageControl(u8);
+	impl Debug;
+
+	pub u8, charge_voltage, set_charge_voltage : 7, 2;
+}
+
+impl ChargeVoltageControl {
+	/// Build a charge voltage control register from a value in millivolts,
+	/// clamping to the 3504..=4400 mV range the part supports.
+	pub fn from_millivolts(millivolts: u16) -> Self {
+		let mut reg = ChargeVoltageControl(0);
+		reg.set_millivolts(millivolts);
+		reg
+	}
+
+	/// Encode a charge (float) voltage in millivolts. `VREG` has a 3504 mV
+	/// offset and 16 mV steps; out of range values are clamped to the nearest
+	/// legal step.
+	pub fn set_millivolts(&mut self, millivolts: u16) {
+		let millivolts = millivolts.clamp(3504, 4400);
+		self.set_charge_voltage(((millivolts - 3504) / 16) as u8);
+	}
+
+	pub fn to_millivolts(&self) -> u16 {
+		3504 + u16::from(self.charge_voltage()) * 16
+	}
+}
+
+impl Default for ChargeVoltageControl {
+	fn default() -> Self {
+		ChargeVoltageControl::from_millivolts(4208)
+	}
+}
+
+#[derive(Debug)]
+#[allow(unused)]
+#[repr(u8)]
+pub enum WatchdogTimerLimit {
+	Disabled = 0b00,
+	S40      = 0b01,
+	S80      = 0b10,
+	S160     = 0b11,
+}
+
+impl Into<u8> for WatchdogTimerLimit {
+	fn into(self) -> u8 {
+		self as u8
+	}
+}
+
+impl From<u8> for WatchdogTimerLimit {
+	fn from(val: u8) -> Self {
+		unsafe { mem::transmute(val & 0b11) }
+	}
+}
+
+#[derive(Debug)]
+#[allow(unused)]
+#[repr(u8)]
+pub enum ChargeTimer {
+	H5  = 0b00,
+	H8  = 0b01,
+	H12 = 0b10,
+	H20 = 0b11,
+}
+
+impl Into<u8> for ChargeTimer {
+	fn into(self) -> u8 {
+		self as u8
+	}
+}
+
+impl From<u8> for ChargeTimer {
+	fn from(val: u8) -> Self {
+		unsafe { mem::transmute(val & 0b11) }
+	}
+}
+
+bitfield! {
+	pub struct ChargeTerminationTimerControl(u8);
+	impl Debug;
+
+	pub bool, charge_termination_enabled, set_charge_termination_enabled : 7;
+	pub u8, from into WatchdogTimerLimit, watchdog, set_watchdog : 5, 4;
+	pub u8, from into ChargeTimer, charge_timer, set_charge_timer : 2, 1;
+}
+
+impl Default for ChargeTerminationTimerControl {
+	fn default() -> Self {
+		let mut reg = ChargeTerminationTimerControl(0);
+		reg.set_charge_termination_enabled(true);
+		reg.set_watchdog(WatchdogTimerLimit::S40);
+		reg.set_charge_timer(ChargeTimer::H12);
+		reg
+	}
+}
+
+#[derive(Debug)]
+#[allow(unused)]
+#[repr(u8)]
+pub enum ThermalRegulationThreshold {
+	C60  = 0b00,
+	C80  = 0b01,
+	C100 = 0b10,
+	C120 = 0b11,
+}
+
+impl Into<u8> for ThermalRegulationThreshold {
+	fn into(self) -> u8 {
+		self as u8
+	}
+}
+
+impl From<u8> for ThermalRegulationThreshold {
+	fn from(val: u8) -> Self {
+		unsafe { mem::transmute(val & 0b11) }
+	}
+}
+
+bitfield! {
+	pub struct ThermalRegulationControl(u8);
+	impl Debug;
+
+	pub u8, from into ThermalRegulationThreshold, thermal_regulation_threshold, set_thermal_regulation_threshold : 1, 0;
+}
+
+impl Default for ThermalRegulationControl {
+	fn default() -> Self {
+		let mut reg = ThermalRegulationControl(0);
+		reg.set_thermal_regulation_threshold(ThermalRegulationThreshold::C120);
+		reg
+	}
+}
+
 bitfield! {
 	pub struct MiscOperationControl(u8);
 	impl Debug;
@@ -213,7 +464,7 @@ impl Default for MiscOperationControl {
 }
 
 impl<I2C, E> Bq24195<I2C>
-	where I2C: Write<Error = E> {
+	where I2C: Write<Error = E> + WriteRead<Error = E> {
 	/// Create a new driver instance.
 	///
 	/// i2c: An i2c bus connected to the Bq24195 chip. Bq24195 supports both
@@ -221,6 +472,7 @@ impl<I2C, E> Bq24195<I2C>
 	pub fn new(i2c: I2C) -> Self {
 		Self {
 			i2c,
+			thermal: ThermalMitigation::default(),
 		}
 	}
 
@@ -232,10 +484,287 @@ impl<I2C, E> Bq24195<I2C>
 		self.write_register(Register::PowerOnConfiguration, power_on_configuration.0)
 	}
 
+	/// Set the fast-charge current. Only the `ICHG` field is written; the
+	/// `BCOLD`/`FORCE_20PCT` bits are preserved via read-modify-write.
+	pub fn set_charge_current_control(&mut self, charge_current_control: ChargeCurrentControl) -> Result<(), Error<E>> {
+		self.modify_charge_current_control(|reg| reg.set_fast_charge_current(charge_current_control.fast_charge_current()))
+	}
+
+	pub fn set_pre_charge_termination_current_control(&mut self, pre_charge_termination_current_control: PreChargeTerminationCurrentControl) -> Result<(), Error<E>> {
+		self.write_register(Register::PreChargeTerminationCurrentControl, pre_charge_termination_current_control.0)
+	}
+
+	/// Set the charge (float) voltage. Only the `VREG` field is written; the
+	/// `BATLOWV`/`VRECHG` bits are preserved via read-modify-write.
+	pub fn set_charge_voltage_control(&mut self, charge_voltage_control: ChargeVoltageControl) -> Result<(), Error<E>> {
+		self.modify_charge_voltage_control(|reg| reg.set_charge_voltage(charge_voltage_control.charge_voltage()))
+	}
+
+	pub fn set_charge_termination_timer_control(&mut self, charge_termination_timer_control: ChargeTerminationTimerControl) -> Result<(), Error<E>> {
+		self.write_register(Register::ChargeTerminationTimerControl, charge_termination_timer_control.0)
+	}
+
 	pub fn set_misc_operation_control(&mut self, misc_operation_control: MiscOperationControl) -> Result<(), Error<E>> {
 		self.write_register(Register::MiscOperationControl, misc_operation_control.0)
 	}
 
+	/// Read back the current `ChargeTerminationTimerControl` register.
+	pub fn charge_termination_timer_control(&mut self) -> Result<ChargeTerminationTimerControl, Error<E>> {
+		let val = self.read_register(Register::ChargeTerminationTimerControl)?;
+		Ok(ChargeTerminationTimerControl(val))
+	}
+
+	/// Configure the I2C watchdog timer period without disturbing the other
+	/// fields of the charge-termination/timer register.
+	pub fn set_watchdog_timer_limit(&mut self, limit: WatchdogTimerLimit) -> Result<(), Error<E>> {
+		self.modify_charge_termination_timer_control(|reg| reg.set_watchdog(limit))
+	}
+
+	/// Read-modify-write `ChargeTerminationTimerControl`.
+	pub fn modify_charge_termination_timer_control<F>(&mut self, f: F) -> Result<(), Error<E>>
+		where F: FnOnce(&mut ChargeTerminationTimerControl) {
+		let mut reg = self.charge_termination_timer_control()?;
+		f(&mut reg);
+		self.set_charge_termination_timer_control(reg)
+	}
+
+	/// Pet the I2C watchdog. The host must call this within the configured
+	/// watchdog period or the charger reverts its registers to their defaults.
+	/// Issues a read-modify-write so only the `WATCHDOG` reset bit of
+	/// `PowerOnConfiguration` is touched.
+	pub fn kick_watchdog(&mut self) -> Result<(), Error<E>> {
+		self.modify_power_on_configuration(|reg| reg.set_watchdog_reset(true))
+	}
+
+	/// Set the die thermal-regulation threshold. Only the `TREG` field is
+	/// written; the `BOOSTV` boost voltage and `BHOT` boost-mode threshold are
+	/// preserved via read-modify-write.
+	pub fn set_thermal_regulation_control(&mut self, thermal_regulation_control: ThermalRegulationControl) -> Result<(), Error<E>> {
+		self.modify_thermal_regulation_control(|reg| reg.set_thermal_regulation_threshold(thermal_regulation_control.thermal_regulation_threshold()))
+	}
+
+	/// Read-modify-write `ThermalRegulationControl`.
+	pub fn modify_thermal_regulation_control<F>(&mut self, f: F) -> Result<(), Error<E>>
+		where F: FnOnce(&mut ThermalRegulationControl) {
+		let mut reg = self.thermal_regulation_control()?;
+		f(&mut reg);
+		self.write_register(Register::ThermalRegulationControl, reg.0)
+	}
+
+	/// Read back the current `ThermalRegulationControl` register.
+	pub fn thermal_regulation_control(&mut self) -> Result<ThermalRegulationControl, Error<E>> {
+		let val = self.read_register(Register::ThermalRegulationControl)?;
+		Ok(ThermalRegulationControl(val))
+	}
+
+	/// Set the charge-current ceiling the software thermal-mitigation loop is
+	/// allowed to walk back up to. The applied level is pinned to the new
+	/// ceiling so the next [`step_thermal_mitigation`](Self::step_thermal_mitigation)
+	/// starts from a known good point.
+	pub fn set_thermal_mitigation_ceiling(&mut self, ceiling: InputCurrentLimit) -> Result<(), Error<E>> {
+		self.thermal.ceiling = ceiling as u8;
+		self.thermal.applied = self.thermal.ceiling;
+		self.thermal.consecutive_normal = 0;
+		self.thermal.seeded = true;
+		self.apply_thermal_level()
+	}
+
+	/// The user configured charge-current ceiling.
+	pub fn thermal_mitigation_ceiling(&self) -> InputCurrentLimit {
+		InputCurrentLimit::from(self.thermal.ceiling)
+	}
+
+	/// The charge-current level currently applied by the mitigation loop, which
+	/// sits at or below the ceiling when the part is throttling.
+	pub fn thermal_mitigation_level(&self) -> InputCurrentLimit {
+		InputCurrentLimit::from(self.thermal.applied)
+	}
+
+	/// Poll the chip once and nudge the input current limit to keep the part
+	/// out of thermal regulation. Call this periodically from the host.
+	///
+	/// When the die is in thermal regulation or the thermistor reports `Hot`
+	/// the limit is dropped one step (never below the minimum); after two
+	/// consecutive `Normal` polls it is raised one step back towards the
+	/// configured ceiling, stepping gently to avoid oscillation.
+	pub fn step_thermal_mitigation(&mut self) -> Result<(), Error<E>> {
+		self.seed_thermal_from_chip()?;
+
+		let status = self.system_status()?;
+		let fault = self.fault()?;
+
+		let throttling = matches!(status.thermal_status(), ThermalStatus::Regulated)
+			|| matches!(fault.thermistor_fault(), ThermistorFault::Hot);
+
+		if throttling {
+			self.thermal.consecutive_normal = 0;
+			if self.thermal.applied > 0 {
+				self.thermal.applied -= 1;
+				return self.apply_thermal_level();
+			}
+		} else {
+			self.thermal.consecutive_normal = self.thermal.consecutive_normal.saturating_add(1);
+			if self.thermal.consecutive_normal >= 2 && self.thermal.applied < self.thermal.ceiling {
+				self.thermal.consecutive_normal = 0;
+				self.thermal.applied += 1;
+				return self.apply_thermal_level();
+			}
+		}
+
+		Ok(())
+	}
+
+	fn apply_thermal_level(&mut self) -> Result<(), Error<E>> {
+		let limit = InputCurrentLimit::from(self.thermal.applied);
+		self.modify_input_source_control(|reg| reg.set_input_current_limit(limit))
+	}
+
+	/// Seed the cached ladder index from the chip's real input current limit on
+	/// first use, so stepping starts from the live value rather than a
+	/// placeholder. Without an explicitly configured ceiling the live value
+	/// also becomes the walk-up ceiling.
+	fn seed_thermal_from_chip(&mut self) -> Result<(), Error<E>> {
+		if self.thermal.seeded {
+			return Ok(());
+		}
+		let limit = self.input_source_control()?.input_current_limit() as u8;
+		self.thermal.applied = limit;
+		self.thermal.ceiling = limit;
+		self.thermal.seeded = true;
+		Ok(())
+	}
+
+	/// Run D+/D- detection and apply a sensible input current limit for the
+	/// detected cable, returning the detected [`VbusStatus`] and the limit
+	/// actually applied (`None` when the cable type is left alone).
+	///
+	/// `UsbHost` is limited to 500 mA and an `Adapter` to 2000 mA; `Unknown`
+	/// and `Otg` leave the current limit untouched. The limit is applied
+	/// through the read-modify-write path so `HIZ` and the input voltage limit
+	/// are preserved, and the thermal-mitigation ceiling is reconciled so a
+	/// later [`step_thermal_mitigation`](Self::step_thermal_mitigation) can't
+	/// raise current back above the detected limit.
+	///
+	/// `delay` is called between polls of the self-clearing `FORCE_DPDM` bit
+	/// so the bus isn't hammered; if detection hasn't finished after `retries`
+	/// polls [`Error::DetectionTimeout`] is returned rather than reading a
+	/// stale `VbusStatus`.
+	pub fn detect_and_apply_input_limit<D>(&mut self, retries: u8, mut delay: D) -> Result<(VbusStatus, Option<InputCurrentLimit>), Error<E>>
+		where D: FnMut() {
+		self.modify_misc_operation_control(|reg| reg.set_dpdm_detection(true))?;
+
+		// FORCE_DPDM self-clears once detection finishes.
+		let mut cleared = false;
+		for _ in 0..retries {
+			if !self.misc_operation_control()?.dpdm_detection() {
+				cleared = true;
+				break;
+			}
+			delay();
+		}
+
+		if !cleared {
+			return Err(Error::DetectionTimeout);
+		}
+
+		let vbus_status = self.system_status()?.vbus_status();
+
+		let index = match vbus_status {
+			VbusStatus::UsbHost => Some(InputCurrentLimit::MA500 as u8),
+			VbusStatus::Adapter => Some(InputCurrentLimit::MA2000 as u8),
+			VbusStatus::Unknown | VbusStatus::Otg => None,
+		};
+
+		if let Some(index) = index {
+			// Route through the thermal cache so the two features agree on the
+			// applied limit and the mitigation loop won't walk current back up
+			// past what detection negotiated.
+			self.thermal.ceiling = index;
+			self.thermal.applied = index;
+			self.thermal.consecutive_normal = 0;
+			self.thermal.seeded = true;
+			self.apply_thermal_level()?;
+		}
+
+		Ok((vbus_status, index.map(InputCurrentLimit::from)))
+	}
+
+	/// Read back the current `InputSourceControl` register.
+	pub fn input_source_control(&mut self) -> Result<InputSourceControl, Error<E>> {
+		let val = self.read_register(Register::InputSourceControl)?;
+		Ok(InputSourceControl(val))
+	}
+
+	/// Read back the current `PowerOnConfiguration` register.
+	pub fn power_on_configuration(&mut self) -> Result<PowerOnConfiguration, Error<E>> {
+		let val = self.read_register(Register::PowerOnConfiguration)?;
+		Ok(PowerOnConfiguration(val))
+	}
+
+	/// Read back the current `MiscOperationControl` register.
+	pub fn misc_operation_control(&mut self) -> Result<MiscOperationControl, Error<E>> {
+		let val = self.read_register(Register::MiscOperationControl)?;
+		Ok(MiscOperationControl(val))
+	}
+
+	/// Read-modify-write `InputSourceControl` so reserved bits and bits set by
+	/// DPDM detection are preserved across the update.
+	pub fn modify_input_source_control<F>(&mut self, f: F) -> Result<(), Error<E>>
+		where F: FnOnce(&mut InputSourceControl) {
+		let mut reg = self.input_source_control()?;
+		f(&mut reg);
+		self.set_input_source_control(reg)
+	}
+
+	/// Read-modify-write `PowerOnConfiguration`, leaving fields the closure
+	/// doesn't touch untouched.
+	pub fn modify_power_on_configuration<F>(&mut self, f: F) -> Result<(), Error<E>>
+		where F: FnOnce(&mut PowerOnConfiguration) {
+		let mut reg = self.power_on_configuration()?;
+		f(&mut reg);
+		self.set_power_on_configuration(reg)
+	}
+
+	/// Read-modify-write `MiscOperationControl`, leaving fields the closure
+	/// doesn't touch untouched.
+	pub fn modify_misc_operation_control<F>(&mut self, f: F) -> Result<(), Error<E>>
+		where F: FnOnce(&mut MiscOperationControl) {
+		let mut reg = self.misc_operation_control()?;
+		f(&mut reg);
+		self.set_misc_operation_control(reg)
+	}
+
+	/// Read back the current `ChargeCurrentControl` register.
+	pub fn charge_current_control(&mut self) -> Result<ChargeCurrentControl, Error<E>> {
+		let val = self.read_register(Register::ChargeCurrentControl)?;
+		Ok(ChargeCurrentControl(val))
+	}
+
+	/// Read-modify-write `ChargeCurrentControl` so the `BCOLD`/`FORCE_20PCT`
+	/// bits the bitfield doesn't model aren't clobbered when only `ICHG`
+	/// changes.
+	pub fn modify_charge_current_control<F>(&mut self, f: F) -> Result<(), Error<E>>
+		where F: FnOnce(&mut ChargeCurrentControl) {
+		let mut reg = self.charge_current_control()?;
+		f(&mut reg);
+		self.write_register(Register::ChargeCurrentControl, reg.0)
+	}
+
+	/// Read back the current `ChargeVoltageControl` register.
+	pub fn charge_voltage_control(&mut self) -> Result<ChargeVoltageControl, Error<E>> {
+		let val = self.read_register(Register::ChargeVoltageControl)?;
+		Ok(ChargeVoltageControl(val))
+	}
+
+	/// Read-modify-write `ChargeVoltageControl` so the `BATLOWV`/`VRECHG` bits
+	/// the bitfield doesn't model aren't clobbered when only `VREG` changes.
+	pub fn modify_charge_voltage_control<F>(&mut self, f: F) -> Result<(), Error<E>>
+		where F: FnOnce(&mut ChargeVoltageControl) {
+		let mut reg = self.charge_voltage_control()?;
+		f(&mut reg);
+		self.write_register(Register::ChargeVoltageControl, reg.0)
+	}
+
 	fn write_register(&mut self, register: Register, value: u8) -> Result<(), Error<E>> {
         self.i2c
             .write(ADDRESS, &[register as u8, value])
@@ -409,6 +938,63 @@ bitfield! {
 	pub u8, into ThermistorFault, thermistor_fault, _ : 2, 0;
 }
 
+#[derive(Debug)]
+pub enum ChargingState {
+	/// No input power, running from the battery.
+	Discharging,
+	/// Input power present but not charging.
+	NotCharging,
+	PreCharge,
+	FastCharge,
+	/// Charge complete.
+	Full,
+}
+
+#[derive(Debug)]
+pub enum Health {
+	Good,
+	OverVoltage,
+	OverHeat,
+	Cold,
+	InputFault,
+	SafetyTimerExpired,
+	WatchdogExpired,
+}
+
+/// A single, `power_supply` style view of the charger combining the
+/// `SystemStatus` and `Fault` registers so integrators don't have to decode
+/// both themselves.
+#[derive(Debug)]
+pub struct ChargerState {
+	pub charging_state: ChargingState,
+	pub health: Health,
+	pub power_good: bool,
+	pub in_vindpm: bool,
+	pub thermal_regulation: bool,
+	pub vsys_regulated: bool,
+}
+
+/// Collapse the `Fault` register's individual fault bits into a single health
+/// verdict, reported most severe first.
+fn fold_health(fault: &Fault) -> Health {
+	if matches!(fault.watchdog(), Watchdog::Expired) {
+		Health::WatchdogExpired
+	} else if matches!(fault.battery_fault(), BatteryFault::OverVoltageThreshold) {
+		Health::OverVoltage
+	} else {
+		match fault.charge_fault() {
+			ChargeFault::InputFault         => Health::InputFault,
+			ChargeFault::ThermalShutdown    => Health::OverHeat,
+			ChargeFault::SafetyTimerExpired => Health::SafetyTimerExpired,
+			ChargeFault::Normal => match fault.thermistor_fault() {
+				ThermistorFault::Hot  => Health::OverHeat,
+				ThermistorFault::Cold => Health::Cold,
+				_                     => Health::Good,
+			},
+		}
+	}
+}
+
 impl<I2C, E> Bq24195<I2C>
 	where I2C: WriteRead<Error = E> {
 	pub fn system_status(&mut self) -> Result<SystemStatus, Error<E>> {
@@ -421,6 +1007,40 @@ impl<I2C, E> Bq24195<I2C>
 		Ok(Fault(val))
 	}
 
+	/// Read `SystemStatus` and `Fault` together and collapse them into a single
+	/// [`ChargerState`]. The two registers are adjacent, so this is a single
+	/// auto-incrementing read rather than two transactions.
+	pub fn status(&mut self) -> Result<ChargerState, Error<E>> {
+		let mut data = [0; 2];
+		self.i2c
+			.write_read(ADDRESS, &[Register::SystemStatus as u8], &mut data)
+			.map_err(Error::I2C)?;
+		let status = SystemStatus(data[0]);
+		let fault = Fault(data[1]);
+
+		let power_good = matches!(status.power_status(), PowerStatus::Good);
+
+		let charging_state = if !power_good {
+			ChargingState::Discharging
+		} else {
+			match status.charge_status() {
+				ChargeStatus::NotCharging => ChargingState::NotCharging,
+				ChargeStatus::PreCharge   => ChargingState::PreCharge,
+				ChargeStatus::FastCharge  => ChargingState::FastCharge,
+				ChargeStatus::ChargeDone  => ChargingState::Full,
+			}
+		};
+
+		Ok(ChargerState {
+			charging_state,
+			health: fold_health(&fault),
+			power_good,
+			in_vindpm: matches!(status.dpm_status(), DpmStatus::Vindpm),
+			thermal_regulation: matches!(status.thermal_status(), ThermalStatus::Regulated),
+			vsys_regulated: matches!(status.vsys_status(), VsysStatus::Regulated),
+		})
+	}
+
 	fn read_register(&mut self, register: Register) -> Result<u8, Error<E>> {
         let mut data = [0; 1];
         self.i2c
@@ -430,10 +1050,161 @@ impl<I2C, E> Bq24195<I2C>
     }
 }
 
+/// Async driver built on [`embedded_hal_async`], for Embassy style firmware
+/// that can't block. It reuses the register enums and `bitfield!` types from
+/// the blocking driver so there's a single source of truth for the encoding.
+#[cfg(feature = "async")]
+pub struct Bq24195Async<I2C> {
+	i2c: I2C,
+}
+
+#[cfg(feature = "async")]
+impl<I2C, E> Bq24195Async<I2C>
+	where I2C: AsyncI2c<Error = E> {
+	/// Create a new async driver instance.
+	///
+	/// i2c: An i2c bus connected to the Bq24195 chip. Bq24195 supports both
+	/// 400khz and 100khz operation.
+	pub fn new(i2c: I2C) -> Self {
+		Self {
+			i2c,
+		}
+	}
+
+	pub async fn set_input_source_control(&mut self, input_source_control: InputSourceControl) -> Result<(), Error<E>> {
+		self.write_register(Register::InputSourceControl, input_source_control.0).await
+	}
+
+	pub async fn set_power_on_configuration(&mut self, power_on_configuration: PowerOnConfiguration) -> Result<(), Error<E>> {
+		self.write_register(Register::PowerOnConfiguration, power_on_configuration.0).await
+	}
+
+	pub async fn set_charge_current_control(&mut self, charge_current_control: ChargeCurrentControl) -> Result<(), Error<E>> {
+		self.write_register(Register::ChargeCurrentControl, charge_current_control.0).await
+	}
+
+	pub async fn set_pre_charge_termination_current_control(&mut self, pre_charge_termination_current_control: PreChargeTerminationCurrentControl) -> Result<(), Error<E>> {
+		self.write_register(Register::PreChargeTerminationCurrentControl, pre_charge_termination_current_control.0).await
+	}
+
+	pub async fn set_charge_voltage_control(&mut self, charge_voltage_control: ChargeVoltageControl) -> Result<(), Error<E>> {
+		self.write_register(Register::ChargeVoltageControl, charge_voltage_control.0).await
+	}
+
+	pub async fn set_misc_operation_control(&mut self, misc_operation_control: MiscOperationControl) -> Result<(), Error<E>> {
+		self.write_register(Register::MiscOperationControl, misc_operation_control.0).await
+	}
+
+	/// Read back the current `InputSourceControl` register.
+	pub async fn input_source_control(&mut self) -> Result<InputSourceControl, Error<E>> {
+		let val = self.read_register(Register::InputSourceControl).await?;
+		Ok(InputSourceControl(val))
+	}
+
+	/// Read back the current `PowerOnConfiguration` register.
+	pub async fn power_on_configuration(&mut self) -> Result<PowerOnConfiguration, Error<E>> {
+		let val = self.read_register(Register::PowerOnConfiguration).await?;
+		Ok(PowerOnConfiguration(val))
+	}
+
+	/// Read back the current `MiscOperationControl` register.
+	pub async fn misc_operation_control(&mut self) -> Result<MiscOperationControl, Error<E>> {
+		let val = self.read_register(Register::MiscOperationControl).await?;
+		Ok(MiscOperationControl(val))
+	}
+
+	pub async fn system_status(&mut self) -> Result<SystemStatus, Error<E>> {
+		let val = self.read_register(Register::SystemStatus).await?;
+		Ok(SystemStatus(val))
+	}
+
+	pub async fn fault(&mut self) -> Result<Fault, Error<E>> {
+		let val = self.read_register(Register::Fault).await?;
+		Ok(Fault(val))
+	}
+
+	async fn read_register(&mut self, register: Register) -> Result<u8, Error<E>> {
+		let mut data = [0; 1];
+		self.i2c
+			.write_read(ADDRESS, &[register as u8], &mut data)
+			.await
+			.map_err(Error::I2C)?;
+		Ok(data[0])
+	}
+
+	async fn write_register(&mut self, register: Register, value: u8) -> Result<(), Error<E>> {
+		self.i2c
+			.write(ADDRESS, &[register as u8, value])
+			.await
+			.map_err(Error::I2C)?;
+		Ok(())
+	}
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn charge_current_round_trips() {
+        assert_eq!(ChargeCurrentControl::from_milliamps(512).to_milliamps(), 512);
+        assert_eq!(ChargeCurrentControl::from_milliamps(2048).to_milliamps(), 2048);
+        assert_eq!(ChargeCurrentControl::from_milliamps(4544).to_milliamps(), 4544);
+        // Non step aligned inputs round down to the nearest 64 mA step.
+        assert_eq!(ChargeCurrentControl::from_milliamps(600).to_milliamps(), 576);
+        // Out of range inputs clamp to the legal extremes.
+        assert_eq!(ChargeCurrentControl::from_milliamps(0).to_milliamps(), 512);
+        assert_eq!(ChargeCurrentControl::from_milliamps(9000).to_milliamps(), 4544);
+    }
+
+    #[test]
+    fn charge_voltage_round_trips() {
+        assert_eq!(ChargeVoltageControl::from_millivolts(3504).to_millivolts(), 3504);
+        assert_eq!(ChargeVoltageControl::from_millivolts(4400).to_millivolts(), 4400);
+        // Round down to the nearest 16 mV step.
+        assert_eq!(ChargeVoltageControl::from_millivolts(3511).to_millivolts(), 3504);
+        // Clamp out of range inputs.
+        assert_eq!(ChargeVoltageControl::from_millivolts(0).to_millivolts(), 3504);
+        assert_eq!(ChargeVoltageControl::from_millivolts(5000).to_millivolts(), 4400);
+    }
+
     #[test]
-    fn it_works() {
-        assert_eq!(2 + 2, 4);
+    fn precharge_termination_round_trips() {
+        let reg = PreChargeTerminationCurrentControl::from_milliamps(256, 128);
+        assert_eq!(reg.precharge_to_milliamps(), 256);
+        assert_eq!(reg.termination_to_milliamps(), 128);
+        // 128 mA steps, clamped to 128..=2048 mA.
+        let reg = PreChargeTerminationCurrentControl::from_milliamps(0, 9000);
+        assert_eq!(reg.precharge_to_milliamps(), 128);
+        assert_eq!(reg.termination_to_milliamps(), 2048);
+    }
+
+    #[test]
+    fn health_folds_most_severe_fault_first() {
+        // Watchdog expiry wins even when other faults are present.
+        let mut fault = Fault(0);
+        fault.0 |= 0b1000_0000; // watchdog
+        fault.0 |= 0b0000_1000; // battery over voltage
+        assert!(matches!(fold_health(&fault), Health::WatchdogExpired));
+
+        // Battery over voltage outranks a charge fault.
+        let mut fault = Fault(0);
+        fault.0 |= 0b0000_1000; // battery over voltage
+        fault.0 |= 0b0001_0000; // charge fault: input fault
+        assert!(matches!(fold_health(&fault), Health::OverVoltage));
+
+        let mut fault = Fault(0);
+        fault.0 |= 0b0010_0000; // charge fault: thermal shutdown
+        assert!(matches!(fold_health(&fault), Health::OverHeat));
+
+        let mut fault = Fault(0);
+        fault.0 |= 0b110; // thermistor hot
+        assert!(matches!(fold_health(&fault), Health::OverHeat));
+
+        let mut fault = Fault(0);
+        fault.0 |= 0b101; // thermistor cold
+        assert!(matches!(fold_health(&fault), Health::Cold));
+
+        assert!(matches!(fold_health(&Fault(0)), Health::Good));
     }
 }